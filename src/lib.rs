@@ -1,6 +1,129 @@
 #[doc(hidden)]
 pub use paste;
 
+// `serialize_field` requires a `&'static str` key, so a `rename_all`/`as "..."` rename
+// can't be resolved into an owned `String` at serialize time — it has to be computed once,
+// at compile time, as a `const`. These two helpers do that: the first sizes the output byte
+// array, the second fills it; `dto_serialize` strings them together as `from_utf8(&BYTES)`
+// into a local `const KEY: &str`, which is implicitly `'static`.
+#[doc(hidden)]
+pub const fn __impl_dto_rename_len(case: &str, key: &str) -> usize {
+    let bytes = key.as_bytes();
+    if !__impl_dto_rename_strips_sep(case) {
+        return bytes.len();
+    }
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'_' {
+            len += 1;
+        }
+        i += 1;
+    }
+    len
+}
+
+#[doc(hidden)]
+pub const fn __impl_dto_rename_bytes<const N: usize>(case: &str, key: &str) -> [u8; N] {
+    let bytes = key.as_bytes();
+    let mut out = [0u8; N];
+    let strip_sep = __impl_dto_rename_strips_sep(case);
+    let kebab_sep = matches!(case.as_bytes(), b"kebab-case" | b"SCREAMING-KEBAB-CASE");
+    let all_upper = matches!(case.as_bytes(), b"UPPERCASE" | b"SCREAMING_SNAKE_CASE" | b"SCREAMING-KEBAB-CASE");
+    let all_lower = matches!(case.as_bytes(), b"lowercase");
+    let pascal = matches!(case.as_bytes(), b"PascalCase");
+    let camel = matches!(case.as_bytes(), b"camelCase");
+
+    let mut read = 0;
+    let mut write = 0;
+    let mut at_word_start = true;
+    let mut first_word = true;
+    while read < bytes.len() {
+        let b = bytes[read];
+        if b == b'_' {
+            if !strip_sep {
+                out[write] = if kebab_sep { b'-' } else { b };
+                write += 1;
+            }
+            at_word_start = true;
+            read += 1;
+            continue;
+        }
+
+        let capitalize = (pascal && at_word_start) || (camel && at_word_start && !first_word);
+        out[write] = if capitalize || all_upper {
+            __impl_dto_to_ascii_upper(b)
+        } else if all_lower || (camel && at_word_start && first_word) {
+            __impl_dto_to_ascii_lower(b)
+        } else {
+            b
+        };
+        write += 1;
+        at_word_start = false;
+        first_word = false;
+        read += 1;
+    }
+    out
+}
+
+// `$field: Vec<ChildDto> = path as ChildDto` (and the `Option`/bare forms) capture the
+// declared field type and the `as`-named DTO as separate fragments, so nothing stops them
+// from naming two different types. This compares their `stringify!`ed tokens byte-for-byte
+// so a mismatch fails the build (a `panic!` in a `const` item) instead of silently
+// serializing with the wrong shape.
+#[doc(hidden)]
+pub const fn __impl_dto_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn __impl_dto_rename_strips_sep(case: &str) -> bool {
+    matches!(case.as_bytes(), b"camelCase" | b"PascalCase")
+}
+
+// `""` (no `#[rename_all]` given) is also accepted here since it flows through the same
+// `$case` slot as an explicit case name.
+#[doc(hidden)]
+pub const fn __impl_dto_is_known_case(case: &str) -> bool {
+    matches!(
+        case.as_bytes(),
+        b"" | b"lowercase"
+            | b"UPPERCASE"
+            | b"PascalCase"
+            | b"camelCase"
+            | b"snake_case"
+            | b"SCREAMING_SNAKE_CASE"
+            | b"kebab-case"
+            | b"SCREAMING-KEBAB-CASE"
+    )
+}
+
+const fn __impl_dto_to_ascii_upper(b: u8) -> u8 {
+    if b.is_ascii_lowercase() {
+        b - 32
+    } else {
+        b
+    }
+}
+
+const fn __impl_dto_to_ascii_lower(b: u8) -> u8 {
+    if b.is_ascii_uppercase() {
+        b + 32
+    } else {
+        b
+    }
+}
+
 /// Implement DTO serialzation wrapper for DBO/Model
 ///
 /// Instead of mapping DBO/Model to DTO then serializing DTO,
@@ -89,23 +212,510 @@ pub use paste;
 ///     );
 /// }
 /// ```
+///
+/// A field can also be annotated with `#[skip_if(...)]`, naming a `fn(&FieldTy) -> bool`
+/// evaluated on the already-mapped value. When it returns `true` the field is omitted from
+/// the output via `state.skip_field(...)` instead of `state.serialize_field(...)`, e.g.:
+///
+/// ```
+/// use ser_mapper::impl_dto;
+///
+/// struct DboModel {
+///     nickname: Option<String>,
+/// }
+///
+/// impl_dto!(
+///     struct Dto<DboModel> {
+///         #[skip_if(Option::is_none)]
+///         nickname: Option<String> = nickname,
+///     }
+/// );
+///
+/// fn main() {
+///     let dto = _Dto(DboModel { nickname: None });
+///     assert_eq!("{}", serde_json::to_string(&dto).unwrap());
+/// }
+/// ```
+///
+/// A single model can also be serialized into several named views instead of one `Dto`.
+/// Declare the fields once, then list `view Name { field1, field2, .. }` blocks picking
+/// which of those fields belong to each view. Every view gets its own DTO struct and the
+/// full family of wrappers (`_DtoPublic`, `_DtoPublicRef`, `_DtoPublicVec`, ...), each with
+/// its own `dto_serialize`, so callers choose the representation at the call site:
+///
+/// ```
+/// use ser_mapper::impl_dto;
+///
+/// struct DboModel {
+///     id: String,
+///     name: String,
+///     email: String,
+/// }
+///
+/// impl_dto!(
+///     struct Dto<DboModel> {
+///         id: String = id,
+///         name: String = name,
+///         email: String = email,
+///
+///         view Public { id, name }
+///         view Admin { id, name, email }
+///     }
+/// );
+///
+/// fn main() {
+///     let dbo = DboModel {
+///         id: String::from("1"),
+///         name: String::from("Jane"),
+///         email: String::from("jane@email.com"),
+///     };
+///
+///     assert_eq!(
+///         r#"{"id":"1","name":"Jane"}"#,
+///         serde_json::to_string(&_DtoPublicRef(&dbo)).unwrap()
+///     );
+///     assert_eq!(
+///         r#"{"id":"1","name":"Jane","email":"jane@email.com"}"#,
+///         serde_json::to_string(&_DtoAdminRef(&dbo)).unwrap()
+///     );
+/// }
+/// ```
+///
+/// A field can also map from the whole entity rather than a single `$($inner_path).+`, by
+/// writing `@self` in place of the path. The closure then receives `&$inner_entity` itself,
+/// which is useful for fields computed from more than one source column:
+///
+/// ```
+/// use ser_mapper::impl_dto;
+///
+/// struct DboModel {
+///     full_name: String,
+///     email: String,
+/// }
+///
+/// impl_dto!(
+///     struct Dto<DboModel> {
+///         display: String = @self => |d: &DboModel| format!("{} <{}>", d.full_name, d.email),
+///     }
+/// );
+///
+/// fn main() {
+///     let dbo = DboModel {
+///         full_name: String::from("Jane Doe"),
+///         email: String::from("jane@email.com"),
+///     };
+///
+///     assert_eq!(
+///         r#"{"display":"Jane Doe <jane@email.com>"}"#,
+///         serde_json::to_string(&_DtoRef(&dbo)).unwrap()
+///     );
+/// }
+/// ```
+///
+/// A field can also point at a child DTO generated by its own `impl_dto!` call, by writing
+/// `as ChildDto` in place of a mapping closure. The wrapper used to serialize it is picked
+/// from the field's declared container: `Vec<ChildDto>` selects `_ChildDtoRefVec`,
+/// `Option<ChildDto>` selects `_ChildDtoRefOption`, and a bare `ChildDto` selects
+/// `_ChildDtoRef`:
+///
+/// ```
+/// use ser_mapper::impl_dto;
+///
+/// struct Role {
+///     name: String,
+/// }
+///
+/// struct DboModel {
+///     id: String,
+///     roles: Vec<Role>,
+///     profile: Option<Role>,
+/// }
+///
+/// impl_dto!(
+///     struct RoleDto<Role> {
+///         name: String = name,
+///     }
+/// );
+///
+/// impl_dto!(
+///     struct Dto<DboModel> {
+///         id: String = id,
+///         roles: Vec<RoleDto> = roles as RoleDto,
+///         profile: Option<RoleDto> = profile as RoleDto,
+///     }
+/// );
+///
+/// fn main() {
+///     let dbo = DboModel {
+///         id: String::from("1"),
+///         roles: vec![Role { name: String::from("admin") }],
+///         profile: Some(Role { name: String::from("owner") }),
+///     };
+///
+///     assert_eq!(
+///         r#"{"id":"1","roles":[{"name":"admin"}],"profile":{"name":"owner"}}"#,
+///         serde_json::to_string(&_DtoRef(&dbo)).unwrap()
+///     );
+/// }
+/// ```
+///
+/// The container attribute `#[rename_all = "camelCase"]` re-cases every field's serialized
+/// key (the Rust field name itself is untouched). A field's own `as "..."` rename always
+/// takes precedence over it. The supported cases mirror `serde`'s `rename_all`: `"lowercase"`,
+/// `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`,
+/// `"kebab-case"` and `"SCREAMING-KEBAB-CASE"`.
+///
+/// ```
+/// use ser_mapper::impl_dto;
+///
+/// struct DboModel {
+///     user_id: String,
+///     full_name: String,
+/// }
+///
+/// impl_dto!(
+///     #[rename_all = "camelCase"]
+///     struct Dto<DboModel> {
+///         user_id: String = user_id,
+///         full_name: String = full_name as "name",
+///     }
+/// );
+///
+/// fn main() {
+///     let dbo = DboModel {
+///         user_id: String::from("1"),
+///         full_name: String::from("Jane Doe"),
+///     };
+///
+///     assert_eq!(
+///         r#"{"userId":"1","name":"Jane Doe"}"#,
+///         serde_json::to_string(&_DtoRef(&dbo)).unwrap()
+///     );
+/// }
+/// ```
 #[macro_export]
 macro_rules! impl_dto {
     (
-        $(#[$m:meta])*
+        #[$($attr:tt)*] $($rest:tt)*
+    ) => {
+        impl_dto!(@scan_container_attrs [] [] #[$($attr)*] $($rest)*);
+    };
+    (
         $vis:vis struct $dto:ident<$inner_entity:ty> {
+            $($fields:tt)*
+        }
+    ) => {
+        impl_dto!(@scan_container_attrs [] [] $vis struct $dto<$inner_entity> { $($fields)* });
+    };
+    // `#[rename_all = "camelCase"]` sets the default key-casing for every field in this
+    // container; like `#[skip_if(...)]` it's pulled out attribute-by-attribute rather than
+    // captured alongside a generic `$(#[$m:meta])*` bucket, which `macro_rules!` treats as
+    // ambiguous. A field's own `as "..."` rename always overrides it.
+    (@scan_container_attrs [$($attrs:tt)*] [$($case:literal)?]
+        #[rename_all = $new_case:literal] $($rest:tt)*
+    ) => {
+        const _: () = if !$crate::__impl_dto_is_known_case($new_case) {
+            panic!(concat!(
+                "impl_dto!: unrecognized `#[rename_all = \"", $new_case, "\"]` — expected one of \
+                 \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\", \
+                 \"SCREAMING_SNAKE_CASE\", \"kebab-case\" or \"SCREAMING-KEBAB-CASE\""
+            ));
+        };
+        impl_dto!(@scan_container_attrs [$($attrs)*] [$new_case] $($rest)*);
+    };
+    (@scan_container_attrs [$($attrs:tt)*] [$($case:literal)?]
+        #[$($attr:tt)*] $($rest:tt)*
+    ) => {
+        impl_dto!(@scan_container_attrs [$($attrs)* #[$($attr)*]] [$($case)?] $($rest)*);
+    };
+    (@scan_container_attrs [$($attrs:tt)*] []
+        $vis:vis struct $dto:ident<$inner_entity:ty> {
+            $($fields:tt)*
+        }
+    ) => {
+        impl_dto!(@scan
+            { $($attrs)* $vis struct $dto<$inner_entity> [""] }
+            []
+            []
+            $($fields)*
+        );
+    };
+    (@scan_container_attrs [$($attrs:tt)*] [$case:literal]
+        $vis:vis struct $dto:ident<$inner_entity:ty> {
+            $($fields:tt)*
+        }
+    ) => {
+        impl_dto!(@scan
+            { $($attrs)* $vis struct $dto<$inner_entity> [$case] }
+            []
+            []
+            $($fields)*
+        );
+    };
+    (@scan $ctx:tt [ $($fields:tt)* ] [ $($views:tt)* ]) => {
+        impl_dto!(@finish $ctx [ $($fields)* ] [ $($views)* ]);
+    };
+    // A `view Name { .. }` block picks a subset of the (once-declared) fields above to
+    // serialize as their own DTO; stash each one away and keep scanning.
+    (@scan $ctx:tt [ $($fields:tt)* ] [ $($views:tt)* ]
+        view $view_name:ident { $($vfield:ident),* $(,)? } $(,)? $($rest:tt)*
+    ) => {
+        impl_dto!(@scan $ctx [ $($fields)* ] [ $($views)* { $view_name [ $($vfield),* ] } ] $($rest)*);
+    };
+    // `#[skip_if(...)]` can't be captured alongside a generic `$(#[$field_m:meta])*`
+    // bucket in one go (macro_rules treats that as ambiguous), so fields are scanned
+    // attribute-by-attribute here, pulling the skip predicate out of the mix before
+    // the field list is handed off for struct/serializer generation.
+    (@scan { $(#[$m:meta])* $vis:vis struct $dto:ident<$inner_entity:ty> [$case:literal] }
+        [ $($fields:tt)* ] [ $($views:tt)* ] $($rest:tt)*
+    ) => {
+        impl_dto!(@scan_attrs { $(#[$m])* $vis struct $dto<$inner_entity> [$case] } [$case]
+            [ $($fields)* ] [ $($views)* ] [] [] $($rest)*);
+    };
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        #[skip_if($skip_fn:path)] $($rest:tt)*
+    ) => {
+        impl_dto!(@scan_attrs $ctx [$case] [ $($done)* ] [ $($views)* ] [$($attrs)*] [$skip_fn] $($rest)*);
+    };
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        #[$($attr:tt)*] $($rest:tt)*
+    ) => {
+        impl_dto!(@scan_attrs $ctx [$case] [ $($done)* ] [ $($views)* ] [$($attrs)* #[$($attr)*]] [$($skip)*] $($rest)*);
+    };
+    // `@self` binds the whole `&$inner_entity` (not a single projected field) to the
+    // mapping closure, for fields computed from more than one source column.
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        $field_vis:vis $field:ident: $field_ty:ty = @self => $fn_expr:expr, $($rest:tt)*
+    ) => {
+        impl_dto!(@scan $ctx
+            [ $($done)* { [$($attrs)*] $field_vis $field: $field_ty ; [$fn_expr] [] [] [] [] [$($skip)*] [$case] [stringify!($field)] } ]
+            [ $($views)* ]
+            $($rest)*
+        );
+    };
+    // `$field: Vec<ChildDto> = path as ChildDto` auto-selects the `_ChildDtoRefVec` wrapper
+    // generated for `ChildDto`, matching `Vec<$child_ty>` by its literal tokens so the field's
+    // container shape can be read before the type is captured as an opaque fragment.
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        $field_vis:vis $field:ident: Vec<$child_ty:ty> = $($inner_path:ident).+ as $child_dto:ident, $($rest:tt)*
+    ) => {
+        const _: () = if !$crate::__impl_dto_str_eq(stringify!($child_ty), stringify!($child_dto)) {
+            panic!(concat!(
+                "impl_dto!: field `", stringify!($field), "` is declared as `Vec<", stringify!($child_ty),
+                ">` but maps `as ", stringify!($child_dto), "` — the element type and the `as` DTO must match"
+            ));
+        };
+        impl_dto!(@scan $ctx
+            [ $($done)* { [$($attrs)*] $field_vis $field: Vec<$child_ty> ; [] [] [{ $child_dto $($inner_path).+ }] [] [] [$($skip)*] [$case] [stringify!($field)] } ]
+            [ $($views)* ]
+            $($rest)*
+        );
+    };
+    // `$field: Option<ChildDto> = path as ChildDto` auto-selects `_ChildDtoRefOption`.
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        $field_vis:vis $field:ident: Option<$child_ty:ty> = $($inner_path:ident).+ as $child_dto:ident, $($rest:tt)*
+    ) => {
+        const _: () = if !$crate::__impl_dto_str_eq(stringify!($child_ty), stringify!($child_dto)) {
+            panic!(concat!(
+                "impl_dto!: field `", stringify!($field), "` is declared as `Option<", stringify!($child_ty),
+                ">` but maps `as ", stringify!($child_dto), "` — the inner type and the `as` DTO must match"
+            ));
+        };
+        impl_dto!(@scan $ctx
+            [ $($done)* { [$($attrs)*] $field_vis $field: Option<$child_ty> ; [] [] [] [{ $child_dto $($inner_path).+ }] [] [$($skip)*] [$case] [stringify!($field)] } ]
+            [ $($views)* ]
+            $($rest)*
+        );
+    };
+    // `path as "key"` renames the emitted key, taking precedence over `rename_all`.
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        $field_vis:vis $field:ident: $field_ty:ty = $($inner_path:ident).+ as $key_lit:literal, $($rest:tt)*
+    ) => {
+        impl_dto!(@scan $ctx
+            [
+                $($done)*
+                {
+                    [$($attrs)*]
+                    $field_vis $field: $field_ty ;
+                    []
+                    [{ $($inner_path).+ }]
+                    []
+                    []
+                    []
+                    [$($skip)*]
+                    [""]
+                    [$key_lit]
+                }
+            ]
+            [ $($views)* ]
+            $($rest)*
+        );
+    };
+    // Bare `$field: ChildDto = path as ChildDto` auto-selects `_ChildDtoRef`.
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        $field_vis:vis $field:ident: $field_ty:ty = $($inner_path:ident).+ as $child_dto:ident, $($rest:tt)*
+    ) => {
+        const _: () = if !$crate::__impl_dto_str_eq(stringify!($field_ty), stringify!($child_dto)) {
+            panic!(concat!(
+                "impl_dto!: field `", stringify!($field), "` is declared as `", stringify!($field_ty),
+                "` but maps `as ", stringify!($child_dto), "` — the field type and the `as` DTO must match"
+            ));
+        };
+        impl_dto!(@scan $ctx
+            [ $($done)* { [$($attrs)*] $field_vis $field: $field_ty ; [] [] [] [] [{ $child_dto $($inner_path).+ }] [$($skip)*] [$case] [stringify!($field)] } ]
+            [ $($views)* ]
+            $($rest)*
+        );
+    };
+    (@scan_attrs $ctx:tt [$case:literal] [ $($done:tt)* ] [ $($views:tt)* ] [$($attrs:tt)*] [$($skip:tt)*]
+        $field_vis:vis $field:ident: $field_ty:ty = $($inner_path:ident).+ $(=> $fn_expr:expr)?, $($rest:tt)*
+    ) => {
+        impl_dto!(@scan $ctx
+            [
+                $($done)*
+                {
+                    [$($attrs)*]
+                    $field_vis $field: $field_ty ;
+                    []
+                    [{ $($inner_path).+ $(=> $fn_expr)? }]
+                    []
+                    []
+                    []
+                    [$($skip)*]
+                    [$case]
+                    [stringify!($field)]
+                }
+            ]
+            [ $($views)* ]
+            $($rest)*
+        );
+    };
+    // No views declared: the field list *is* the DTO, same as before views existed.
+    (@finish $ctx:tt [ $($fields:tt)* ] []) => {
+        impl_dto!(@emit_view $ctx [ $($fields)* ]);
+    };
+    // One or more views: resolve each view's field names against the full field list and
+    // emit one DTO (and wrapper family) per view.
+    (@finish $ctx:tt [ $($fields:tt)* ] [ $($views:tt)* ]) => {
+        impl_dto!(@gen_resolver $ctx [ $($fields)* ] [ $($views)* ]);
+    };
+    (@gen_resolver
+        { $(#[$m:meta])* $vis:vis struct $dto:ident<$inner_entity:ty> [$case:literal] }
+        [
             $(
-                $(#[$field_m:meta])*
-                $field_vis:vis $field:ident: $field_ty:ty = $($inner_path:ident).+ $(=> $fn_expr:expr)?,
+                {
+                    [$(#[$clean_attr:meta])*]
+                    $field_vis:vis $field:ident: $field_ty:ty ;
+                    $($meta:tt)*
+                }
             )*
+        ]
+        [ $($views:tt)* ]
+    ) => {
+        $crate::paste::paste! {
+            impl_dto!(@gen_resolver_inner
+                { $(#[$m])* $vis struct $dto<$inner_entity> [$case] }
+                [
+                    $(
+                        {
+                            [$(#[$clean_attr])*]
+                            $field_vis $field: $field_ty ;
+                            $($meta)*
+                        }
+                    )*
+                ]
+                [ $($views)* ]
+                [<__impl_dto_resolve_ $dto>],
+                $
+            );
         }
+    };
+    // Builds a private, per-invocation `macro_rules!` that resolves a field name (matched
+    // literally, one arm per known field) to its full scanned record, so each view can pull
+    // the fields it names out of the field list declared once at the top.
+    (@gen_resolver_inner
+        $ctx:tt
+        [
+            $(
+                {
+                    [$(#[$clean_attr:meta])*]
+                    $field_vis:vis $field:ident: $field_ty:ty ;
+                    $($meta:tt)*
+                }
+            )*
+        ]
+        [ $( { $view_name:ident [ $($vfield:ident),* ] } )* ]
+        $resolver:ident, $d:tt
     ) => {
+        macro_rules! $resolver {
+            $(
+                ($field, $d acc:tt, $d ctx:tt, $d rest:tt) => {
+                    impl_dto!(@resolve_push $resolver $d acc
+                        [
+                            {
+                                [$(#[$clean_attr])*]
+                                $field_vis $field: $field_ty ;
+                                $($meta)*
+                            }
+                        ]
+                        $d ctx $d rest
+                    );
+                };
+            )*
+            ($d other:ident, $d acc:tt, $d ctx:tt, $d rest:tt) => {
+                compile_error!(concat!("impl_dto!: view names unknown field `", stringify!($d other), "`"));
+            };
+        }
 
+        $(
+            impl_dto!(@build_view_ctx $ctx $resolver, $view_name ; $($vfield)*);
+        )*
+    };
+    (@build_view_ctx
+        { $(#[$m:meta])* $vis:vis struct $dto:ident<$inner_entity:ty> [$case:literal] }
+        $resolver:ident, $view_name:ident ; $($vfield:ident)*
+    ) => {
+        $crate::paste::paste! {
+            impl_dto!(@resolve_view $resolver ;
+                { $(#[$m])* $vis struct [<$dto $view_name>]<$inner_entity> [$case] }
+                []
+                $($vfield)*
+            );
+        }
+    };
+    (@resolve_push $resolver:ident [ $($acc:tt)* ] [ $($new:tt)* ] $ctx:tt [ $($rest:tt)* ]) => {
+        impl_dto!(@resolve_view $resolver ; $ctx [ $($acc)* $($new)* ] $($rest)*);
+    };
+    (@resolve_view $resolver:ident ; $ctx:tt [ $($acc:tt)* ]) => {
+        impl_dto!(@emit_view $ctx [ $($acc)* ]);
+    };
+    (@resolve_view $resolver:ident ; $ctx:tt [ $($acc:tt)* ] $field_name:ident $($rest:ident)*) => {
+        $resolver!($field_name, [ $($acc)* ], $ctx, [ $($rest)* ]);
+    };
+    (@emit_view
+        { $(#[$m:meta])* $vis:vis struct $dto:ident<$inner_entity:ty> [$case:literal] }
+        [
+            $(
+                {
+                    [$(#[$clean_attr:meta])*]
+                    $field_vis:vis $field:ident: $field_ty:ty ;
+                    [$($self_fn_expr:expr)?]
+                    [$({ $($inner_path:ident).+ $(=> $fn_expr:expr)? })?]
+                    [$({ $vec_child_dto:ident $($vec_inner_path:ident).+ })?]
+                    [$({ $opt_child_dto:ident $($opt_inner_path:ident).+ })?]
+                    [$({ $dir_child_dto:ident $($dir_inner_path:ident).+ })?]
+                    [$($skip_fn:path)?]
+                    [$eff_case:literal]
+                    [$eff_base:expr]
+                }
+            )*
+        ]
+    ) => {
         impl_dto!(@define_dto
             $(#[$m])*
             $vis struct $dto<$inner_entity> {
                 $(
-                    $(#[$field_m])*
+                    $(#[$clean_attr])*
                     $field_vis $field: $field_ty,
                 )*
             }
@@ -119,12 +729,46 @@ macro_rules! impl_dto {
                 {
                     use serde::ser::SerializeStruct;
 
-                    let mut state = serializer.serialize_struct(stringify!($dto), impl_dto!(@count $($field),+))?;
+                    // An empty view never calls `serialize_field`/`skip_field`, so `state`
+                    // would otherwise go unused as `mut`.
+                    #[allow(unused_mut)]
+                    let mut state = serializer.serialize_struct(stringify!($dto), impl_dto!(@count $($field),*))?;
                     $(
                         {
-                            let value = &self.$($inner_path).+;
-                            let value = $($fn_expr)?(value);
-                            state.serialize_field(stringify!($field), &value)?;
+                            const KEY: &str = {
+                                const BASE: &str = $eff_base;
+                                const LEN: usize = $crate::__impl_dto_rename_len($eff_case, BASE);
+                                const BYTES: [u8; LEN] = $crate::__impl_dto_rename_bytes::<LEN>($eff_case, BASE);
+                                match core::str::from_utf8(&BYTES) {
+                                    Ok(s) => s,
+                                    Err(_) => unreachable!(),
+                                }
+                            };
+
+                            $(
+                                let value = ($self_fn_expr)(self);
+                            )?
+                            $(
+                                let value = &self.$($inner_path).+;
+                                let value = $($fn_expr)?(value);
+                            )?
+                            $(
+                                let value = [<_ $vec_child_dto RefVec>](self.$($vec_inner_path).+.iter().collect());
+                            )?
+                            $(
+                                let value = [<_ $opt_child_dto RefOption>](self.$($opt_inner_path).+.as_ref());
+                            )?
+                            $(
+                                let value = [<_ $dir_child_dto Ref>](&self.$($dir_inner_path).+);
+                            )?
+                            $(
+                                if $skip_fn(&value) {
+                                    state.skip_field(KEY)?;
+                                } else
+                            )?
+                            {
+                                state.serialize_field(KEY, &value)?;
+                            }
                         }
                     )*
                     state.end()
@@ -148,7 +792,7 @@ macro_rules! impl_dto {
             $(
                 $(#[$field_m])*
                 $field_vis $field: $field_ty,
-            )+
+            )*
         }
 
         $crate::paste::paste! {
@@ -172,7 +816,7 @@ macro_rules! impl_dto {
                 where
                     S: serde::Serializer,
                 {
-                    self.0.dto_serialize(serializer)
+                    <$inner_entity as [<$dto Serializer>]>::dto_serialize(&self.0, serializer)
                 }
             }
             impl<'a> serde::Serialize for [<_ $dto Ref>]<'a> {
@@ -180,7 +824,7 @@ macro_rules! impl_dto {
                 where
                     S: serde::Serializer,
                 {
-                    self.0.dto_serialize(serializer)
+                    <$inner_entity as [<$dto Serializer>]>::dto_serialize(self.0, serializer)
                 }
             }
             impl serde::Serialize for [<_ $dto Option>] {
@@ -258,6 +902,8 @@ macro_rules! impl_dto {
     };
     (@count $t1:tt, $($t:tt),+) => { 1 + impl_dto!(@count $($t),+) };
     (@count $t:tt) => { 1 };
+    // An empty `view Name { }` resolves to zero fields here.
+    (@count) => { 0 };
 }
 
 #[cfg(test)]
@@ -319,7 +965,7 @@ mod tests {
     );
 
     const EXP_SER: &str =
-        r#"{"user_id":"abcd_123","first_name":"Hello","last_name":"world!","age":69}"#;
+        r#"{"user_id":"abdc_123","first_name":"Hello","last_name":"world!","age":69}"#;
     const EXP_NULL: &str = "null";
 
     #[test]
@@ -389,4 +1035,243 @@ mod tests {
 
         assert_eq!(format!("[{EXP_SER}]"), serde_json::to_string(&dto).unwrap());
     }
+
+    // Define DTO views sharing the same field list
+    impl_dto!(
+        #[derive(Debug)]
+        struct Multi<Dbo> {
+            user_id: String = id => _IdRef,
+            age: u8 = age,
+
+            view Compact { user_id }
+            view Full { user_id, age }
+            view Empty { }
+        }
+    );
+
+    #[test]
+    fn test_ser_mapper_view_compact() {
+        let dbo = Dbo::new();
+        let dto = _MultiCompactRef(&dbo);
+
+        assert_eq!(r#"{"user_id":"abdc_123"}"#, serde_json::to_string(&dto).unwrap());
+    }
+
+    #[test]
+    fn test_ser_mapper_view_full() {
+        let dbo = Dbo::new();
+        let dto = _MultiFullRef(&dbo);
+
+        assert_eq!(
+            r#"{"user_id":"abdc_123","age":69}"#,
+            serde_json::to_string(&dto).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ser_mapper_view_empty() {
+        let dbo = Dbo::new();
+        let dto = _MultiEmptyRef(&dbo);
+
+        assert_eq!("{}", serde_json::to_string(&dto).unwrap());
+    }
+
+    // Define a DTO with a field computed from the whole entity
+    impl_dto!(
+        #[derive(Debug)]
+        struct SelfMapped<Dbo> {
+            summary: String = @self => |d: &Dbo| format!("{} ({})", d.full_name, d.age),
+        }
+    );
+
+    #[test]
+    fn test_ser_mapper_self() {
+        let dbo = Dbo::new();
+        let dto = _SelfMappedRef(&dbo);
+
+        assert_eq!(
+            r#"{"summary":"Hello world! (69)"}"#,
+            serde_json::to_string(&dto).unwrap()
+        );
+    }
+
+    #[derive(Debug)]
+    struct WithNicknameDbo {
+        nickname: Option<String>,
+        age: u8,
+    }
+
+    // Define a DTO with a field skipped via `#[skip_if(...)]`
+    impl_dto!(
+        #[derive(Debug)]
+        struct WithNickname<WithNicknameDbo> {
+            #[skip_if(Option::is_none)]
+            nickname: Option<String> = nickname,
+            age: u8 = age,
+        }
+    );
+
+    #[test]
+    fn test_ser_mapper_skip_if() {
+        let dbo_some = WithNicknameDbo {
+            nickname: Some(String::from("Johnny")),
+            age: 69,
+        };
+        let dto_some = _WithNicknameRef(&dbo_some);
+        assert_eq!(
+            r#"{"nickname":"Johnny","age":69}"#,
+            serde_json::to_string(&dto_some).unwrap()
+        );
+
+        let dbo_none = WithNicknameDbo { nickname: None, age: 69 };
+        let dto_none = _WithNicknameRef(&dbo_none);
+        assert_eq!(r#"{"age":69}"#, serde_json::to_string(&dto_none).unwrap());
+    }
+
+    #[derive(Debug)]
+    struct Role {
+        name: String,
+    }
+
+    impl_dto!(
+        #[derive(Debug)]
+        struct RoleDto<Role> {
+            name: String = name,
+        }
+    );
+
+    #[derive(Debug)]
+    struct WithRoles {
+        id: String,
+        roles: Vec<Role>,
+        lead: Option<Role>,
+    }
+
+    // Define a DTO with fields mapped to a child DTO via `as ChildDto`
+    impl_dto!(
+        #[derive(Debug)]
+        struct Nested<WithRoles> {
+            id: String = id,
+            roles: Vec<RoleDto> = roles as RoleDto,
+            lead: Option<RoleDto> = lead as RoleDto,
+        }
+    );
+
+    #[test]
+    fn test_ser_mapper_nested() {
+        let dbo = WithRoles {
+            id: String::from("1"),
+            roles: vec![Role { name: String::from("admin") }, Role { name: String::from("editor") }],
+            lead: Some(Role { name: String::from("admin") }),
+        };
+        let dto = _NestedRef(&dbo);
+
+        assert_eq!(
+            r#"{"id":"1","roles":[{"name":"admin"},{"name":"editor"}],"lead":{"name":"admin"}}"#,
+            serde_json::to_string(&dto).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ser_mapper_nested_none() {
+        let dbo = WithRoles {
+            id: String::from("2"),
+            roles: vec![],
+            lead: None,
+        };
+        let dto = _NestedRef(&dbo);
+
+        assert_eq!(
+            r#"{"id":"2","roles":[],"lead":null}"#,
+            serde_json::to_string(&dto).unwrap()
+        );
+    }
+
+    // Define a DTO with `rename_all` and an explicit per-field `as "..."` override
+    impl_dto!(
+        #[derive(Debug)]
+        #[rename_all = "camelCase"]
+        struct Renamed<Dbo> {
+            user_id: String = id => _IdRef,
+            full_name: String = full_name as "nickname",
+            age: u8 = age,
+        }
+    );
+
+    #[test]
+    fn test_ser_mapper_rename_all() {
+        let dbo = Dbo::new();
+        let dto = _RenamedRef(&dbo);
+
+        assert_eq!(
+            r#"{"userId":"abdc_123","nickname":"Hello world!","age":69}"#,
+            serde_json::to_string(&dto).unwrap()
+        );
+    }
+
+    // One single-field DTO per remaining `rename_all` case (`camelCase` is covered by
+    // `Renamed` above), mapping the same multi-word `full_name` so every case's separator
+    // handling (stripped vs. kept) is actually exercised.
+    impl_dto!(
+        #[rename_all = "lowercase"]
+        struct RenamedLower<Dbo> { full_name: String = full_name, }
+    );
+    impl_dto!(
+        #[rename_all = "UPPERCASE"]
+        struct RenamedUpper<Dbo> { full_name: String = full_name, }
+    );
+    impl_dto!(
+        #[rename_all = "PascalCase"]
+        struct RenamedPascal<Dbo> { full_name: String = full_name, }
+    );
+    impl_dto!(
+        #[rename_all = "snake_case"]
+        struct RenamedSnake<Dbo> { full_name: String = full_name, }
+    );
+    impl_dto!(
+        #[rename_all = "SCREAMING_SNAKE_CASE"]
+        struct RenamedScreamingSnake<Dbo> { full_name: String = full_name, }
+    );
+    impl_dto!(
+        #[rename_all = "kebab-case"]
+        struct RenamedKebab<Dbo> { full_name: String = full_name, }
+    );
+    impl_dto!(
+        #[rename_all = "SCREAMING-KEBAB-CASE"]
+        struct RenamedScreamingKebab<Dbo> { full_name: String = full_name, }
+    );
+
+    #[test]
+    fn test_ser_mapper_rename_all_cases() {
+        let dbo = Dbo::new();
+
+        assert_eq!(
+            r#"{"full_name":"Hello world!"}"#,
+            serde_json::to_string(&_RenamedLowerRef(&dbo)).unwrap()
+        );
+        assert_eq!(
+            r#"{"FULL_NAME":"Hello world!"}"#,
+            serde_json::to_string(&_RenamedUpperRef(&dbo)).unwrap()
+        );
+        assert_eq!(
+            r#"{"FullName":"Hello world!"}"#,
+            serde_json::to_string(&_RenamedPascalRef(&dbo)).unwrap()
+        );
+        assert_eq!(
+            r#"{"full_name":"Hello world!"}"#,
+            serde_json::to_string(&_RenamedSnakeRef(&dbo)).unwrap()
+        );
+        assert_eq!(
+            r#"{"FULL_NAME":"Hello world!"}"#,
+            serde_json::to_string(&_RenamedScreamingSnakeRef(&dbo)).unwrap()
+        );
+        assert_eq!(
+            r#"{"full-name":"Hello world!"}"#,
+            serde_json::to_string(&_RenamedKebabRef(&dbo)).unwrap()
+        );
+        assert_eq!(
+            r#"{"FULL-NAME":"Hello world!"}"#,
+            serde_json::to_string(&_RenamedScreamingKebabRef(&dbo)).unwrap()
+        );
+    }
 }